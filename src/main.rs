@@ -7,18 +7,25 @@ use std::{
     fs::File,
     io,
     path::{Path, PathBuf},
+    process::ExitCode,
 };
 
 use anyhow::Context as _;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use itertools::{EitherOrBoth, Itertools as _};
 use json_schema_diff::Change;
 use nunny::NonEmpty;
-use openrpc_types::{ContentDescriptor, Method, OpenRPC, SpecificationExtensions};
+use openrpc_types::{
+    ContentDescriptor, Error as MethodError, Method, OpenRPC, ParamStructure, ReferenceOr, Server,
+    SpecificationExtensions,
+};
 use schemars::schema::{RootSchema, Schema};
 use serde::Serialize;
 use serde_json::Value;
-use summary::{MethodChange, Summary};
+use summary::{
+    Bump, Changelog, Compatibility, ErrorChange, MethodChange, ParamKey, ParamStructureChange,
+    ParameterChange, Polarity, ServersChange, Summary, Transition,
+};
 
 const NO_DESCRIPTOR: &ContentDescriptor = &ContentDescriptor {
     name: String::new(),
@@ -32,20 +39,101 @@ const NO_DESCRIPTOR: &ContentDescriptor = &ContentDescriptor {
 
 #[derive(Parser)]
 struct Args {
-    left: PathBuf,
-    right: PathBuf,
+    /// An ordered sequence of OpenRPC documents, oldest first.
+    ///
+    /// Each adjacent pair is diffed in turn, producing a cumulative changelog
+    /// across the whole range rather than a single delta.
+    #[arg(required = true, num_args = 2..)]
+    versions: Vec<PathBuf>,
+    /// Output format for the changelog.
+    #[arg(long, value_enum, default_value_t = Format::Yaml)]
+    format: Format,
+    /// Exit with a non-zero status if any transition in the range contains a
+    /// breaking change, so CI can gate on spec compatibility without a
+    /// post-processing script.
+    #[arg(long)]
+    exit_code: bool,
 }
 
-fn main() -> anyhow::Result<()> {
-    let Args { left, right } = Args::parse();
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Yaml,
+    Json,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let Args {
+        versions,
+        format,
+        exit_code,
+    } = Args::parse();
+
+    let prepared = versions
+        .into_iter()
+        .map(prepare)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let transitions = prepared
+        .iter()
+        .tuple_windows()
+        .map(
+            |((from, left_definitions, left_methods), (to, right_definitions, right_methods))| {
+                Transition {
+                    from: from.clone(),
+                    to: to.clone(),
+                    summary: diff_methods(
+                        left_definitions,
+                        left_methods,
+                        right_definitions,
+                        right_methods,
+                    ),
+                }
+            },
+        )
+        .collect::<Vec<_>>();
 
-    let (left_definitions, left_methods) = prepare(left)?;
-    let (right_definitions, right_methods) = prepare(right)?;
+    let (first_label, _, first_methods) = &prepared[0];
+    let history = summary::history(
+        first_label,
+        &first_methods.keys().cloned().collect(),
+        &transitions,
+    );
 
+    let recommended_bump = transitions
+        .iter()
+        .map(|it| it.summary.recommended_bump)
+        .max()
+        .unwrap_or(Bump::Patch);
+
+    let changelog = Changelog {
+        transitions,
+        history,
+        recommended_bump,
+    };
+
+    match format {
+        Format::Yaml => serde_yaml::to_writer(io::stdout(), &changelog)?,
+        Format::Json => serde_json::to_writer_pretty(io::stdout(), &changelog)?,
+    }
+
+    Ok(match exit_code && recommended_bump == Bump::Major {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    })
+}
+
+fn diff_methods(
+    left_definitions: &BTreeMap<String, Schema>,
+    left_methods: &BTreeMap<String, ExtractedMethod>,
+    right_definitions: &BTreeMap<String, Schema>,
+    right_methods: &BTreeMap<String, ExtractedMethod>,
+) -> Summary {
     let left_names = left_methods.keys().collect();
     let right_names = right_methods.keys().collect();
 
     let (only_left, common, only_right) = venn(&left_names, &right_names);
+    let only_left = only_left.map(|it| (*it).clone()).collect::<Vec<_>>();
+    let only_right = only_right.map(|it| (*it).clone()).collect::<Vec<_>>();
 
     let mut methods = BTreeMap::new();
     let mut compatible = Vec::new();
@@ -53,73 +141,153 @@ fn main() -> anyhow::Result<()> {
     for method in common {
         let method = (*method).clone();
 
-        let (left_params, left_return) = &left_methods[&method];
-        let (right_params, right_return) = &right_methods[&method];
+        let (
+            left_params,
+            left_return,
+            left_errors,
+            left_deprecated,
+            left_servers,
+            left_param_structure,
+        ) = &left_methods[&method];
+        let (
+            right_params,
+            right_return,
+            right_errors,
+            right_deprecated,
+            right_servers,
+            right_param_structure,
+        ) = &right_methods[&method];
 
-        let common_length = cmp::max(left_params.len(), right_params.len());
-
-        let param_diffs = nunny::Vec::new(
-            left_params
-                .iter()
-                .pad_using(common_length, |_ix| NO_DESCRIPTOR)
-                .zip(
-                    right_params
-                        .iter()
-                        .pad_using(common_length, |_ix| NO_DESCRIPTOR),
-                )
-                .enumerate()
-                .flat_map(|(ix, (l, r))| {
-                    diff(l, r, &left_definitions, &right_definitions).map(|it| (ix, it))
-                })
-                .collect(),
-        )
-        .ok();
+        let parameter = match_parameters(
+            left_params,
+            right_params,
+            *left_param_structure,
+            *right_param_structure,
+            left_definitions,
+            right_definitions,
+        );
 
         let result_diff = diff(
             left_return.as_ref().unwrap_or(NO_DESCRIPTOR),
             right_return.as_ref().unwrap_or(NO_DESCRIPTOR),
-            &left_definitions,
-            &right_definitions,
+            left_definitions,
+            right_definitions,
         );
 
-        if param_diffs.is_none() && result_diff.is_none() {
+        let left_errors = errors_by_code(left_errors);
+        let right_errors = errors_by_code(right_errors);
+        let left_codes = left_errors.keys().collect();
+        let right_codes = right_errors.keys().collect();
+        let (removed_codes, common_codes, added_codes) = venn(&left_codes, &right_codes);
+
+        let mut errors = BTreeMap::new();
+        errors.extend(removed_codes.map(|code| (**code, ErrorChange::Removed)));
+        errors.extend(added_codes.map(|code| (**code, ErrorChange::Added)));
+        for code in common_codes {
+            if let Some(change) = summary::error_change(
+                left_errors[code],
+                right_errors[code],
+                left_definitions,
+                right_definitions,
+            ) {
+                errors.insert(**code, change);
+            }
+        }
+
+        let newly_deprecated = !left_deprecated && *right_deprecated;
+
+        let servers = (left_servers != right_servers).then(|| ServersChange {
+            left: left_servers.clone(),
+            right: right_servers.clone(),
+        });
+
+        let param_structure =
+            (left_param_structure != right_param_structure).then_some(ParamStructureChange {
+                left: *left_param_structure,
+                right: *right_param_structure,
+            });
+
+        if parameter.is_empty()
+            && result_diff.is_none()
+            && errors.is_empty()
+            && !newly_deprecated
+            && servers.is_none()
+            && param_structure.is_none()
+        {
             compatible.push(method);
             continue;
         }
+
+        let result = result_diff.map(|it| summary::content_descriptor_change(it, Polarity::Result));
+
+        // Newly deprecating a method doesn't break existing callers, but it's a signal they
+        // should migrate before the method is eventually removed, so it's at least a minor bump.
+        // A changed `servers` list is purely informational about where to send requests -- it
+        // doesn't alter the method's wire calling convention -- so it's surfaced but doesn't
+        // itself move the bump.
+        let compatibility = parameter
+            .values()
+            .map(ParameterChange::compatibility)
+            .chain(result.as_ref().map(|it| it.compatibility))
+            .chain(errors.values().map(ErrorChange::compatibility))
+            .chain(param_structure.is_some().then_some(Compatibility::Breaking))
+            .chain(newly_deprecated.then_some(Compatibility::Addition))
+            .max()
+            .unwrap_or(Compatibility::Compatible);
+
         methods.insert(
             method,
             MethodChange {
-                parameter: param_diffs
-                    .into_iter()
-                    .flatten()
-                    .map(|(ix, it)| (ix, it.into()))
-                    .collect(),
-                result: result_diff.map(Into::into),
+                parameter,
+                result,
+                errors,
+                newly_deprecated,
+                servers,
+                param_structure,
+                compatibility,
             },
         );
     }
 
-    let summary = Summary {
+    let recommended_bump: Bump = methods
+        .values()
+        .map(|it| it.compatibility)
+        .chain((!only_left.is_empty()).then_some(Compatibility::Breaking))
+        .chain((!only_right.is_empty()).then_some(Compatibility::Addition))
+        .max()
+        .unwrap_or(Compatibility::Compatible)
+        .into();
+
+    Summary {
         equivalent: compatible,
         different: methods,
-        left: only_left.map(|it| (*it).clone()).collect(),
-        right: only_right.map(|it| (*it).clone()).collect(),
-    };
-
-    serde_yaml::to_writer(io::stdout(), &summary)?;
-
-    Ok(())
+        left: only_left,
+        right: only_right,
+        recommended_bump,
+    }
 }
 
-#[allow(clippy::type_complexity)]
-fn prepare(
-    path: PathBuf,
-) -> anyhow::Result<(
+/// `(params, result, errors, deprecated, servers, param_structure)`
+type ExtractedMethod = (
+    Vec<ContentDescriptor>,
+    Option<ContentDescriptor>,
+    Vec<ReferenceOr<MethodError>>,
+    bool,
+    Vec<Server>,
+    ParamStructure,
+);
+
+/// `(version, definitions, methods)`
+type PreparedDocument = (
+    String,
     BTreeMap<String, Schema>,
-    BTreeMap<String, (Vec<ContentDescriptor>, Option<ContentDescriptor>)>,
-)> {
+    BTreeMap<String, ExtractedMethod>,
+);
+
+fn prepare(path: PathBuf) -> anyhow::Result<PreparedDocument> {
     let mut document = read(path)?;
     rewrite_schema_references::open_rpc(&mut document);
+    let version = document.info.version.clone();
     let definitions = document
         .components
         .unwrap_or_default()
@@ -130,7 +298,7 @@ fn prepare(
         .into_iter()
         .map(method)
         .collect::<BTreeMap<_, _>>();
-    Ok((definitions, methods))
+    Ok((version, definitions, methods))
 }
 
 #[derive(Debug, Serialize)]
@@ -191,7 +359,95 @@ fn venn<'a, T: Ord>(
     (only_left, common, only_right)
 }
 
-fn method(method: Method) -> (String, (Vec<ContentDescriptor>, Option<ContentDescriptor>)) {
+/// Whether there is at least one parameter and every parameter has a non-empty, unique
+/// [`ContentDescriptor::name`], and so can be addressed by name even if the method doesn't
+/// explicitly declare [`ParamStructure::ByName`].
+fn names_distinct(params: &[ContentDescriptor]) -> bool {
+    let names: BTreeSet<&str> = params
+        .iter()
+        .map(|it| it.name.as_str())
+        .filter(|it| !it.is_empty())
+        .collect();
+    !params.is_empty() && names.len() == params.len()
+}
+
+/// Matches `left_params` against `right_params`, by name for by-name methods (or, unless either
+/// side declares [`ParamStructure::ByPosition`], methods whose descriptors already carry distinct
+/// names) and positionally otherwise.
+fn match_parameters(
+    left_params: &[ContentDescriptor],
+    right_params: &[ContentDescriptor],
+    left_param_structure: ParamStructure,
+    right_param_structure: ParamStructure,
+    left_definitions: &BTreeMap<String, Schema>,
+    right_definitions: &BTreeMap<String, Schema>,
+) -> BTreeMap<ParamKey, ParameterChange> {
+    let by_position = matches!(left_param_structure, ParamStructure::ByPosition)
+        || matches!(right_param_structure, ParamStructure::ByPosition);
+    let by_name = matches!(left_param_structure, ParamStructure::ByName)
+        || matches!(right_param_structure, ParamStructure::ByName)
+        || (!by_position && names_distinct(left_params) && names_distinct(right_params));
+
+    if !by_name {
+        let common_length = cmp::max(left_params.len(), right_params.len());
+        return left_params
+            .iter()
+            .pad_using(common_length, |_ix| NO_DESCRIPTOR)
+            .zip(
+                right_params
+                    .iter()
+                    .pad_using(common_length, |_ix| NO_DESCRIPTOR),
+            )
+            .enumerate()
+            .flat_map(|(ix, (l, r))| {
+                diff(l, r, left_definitions, right_definitions).map(|it| {
+                    (
+                        ParamKey::Index(ix),
+                        ParameterChange::Changed(summary::content_descriptor_change(
+                            it,
+                            Polarity::Param,
+                        )),
+                    )
+                })
+            })
+            .collect();
+    }
+
+    let left_by_name: BTreeMap<&String, &ContentDescriptor> =
+        left_params.iter().map(|it| (&it.name, it)).collect();
+    let right_by_name: BTreeMap<&String, &ContentDescriptor> =
+        right_params.iter().map(|it| (&it.name, it)).collect();
+    let left_names = left_by_name.keys().copied().collect();
+    let right_names = right_by_name.keys().copied().collect();
+    let (only_left, common, only_right) = venn(&left_names, &right_names);
+
+    let mut parameter = BTreeMap::new();
+    parameter
+        .extend(only_left.map(|name| (ParamKey::Name((*name).clone()), ParameterChange::Removed)));
+    parameter.extend(only_right.map(|name| {
+        let required = right_by_name[name].required.unwrap_or_default();
+        (
+            ParamKey::Name((*name).clone()),
+            ParameterChange::Added { required },
+        )
+    }));
+    for name in common {
+        if let Some(it) = diff(
+            left_by_name[name],
+            right_by_name[name],
+            left_definitions,
+            right_definitions,
+        ) {
+            parameter.insert(
+                ParamKey::Name((*name).clone()),
+                ParameterChange::Changed(summary::content_descriptor_change(it, Polarity::Param)),
+            );
+        }
+    }
+    parameter
+}
+
+fn method(method: Method) -> (String, ExtractedMethod) {
     let Method {
         name,
         tags: _,
@@ -200,14 +456,38 @@ fn method(method: Method) -> (String, (Vec<ContentDescriptor>, Option<ContentDes
         external_docs: _,
         params,
         result,
-        deprecated: _,
-        servers: _,
-        errors: _,
-        param_structure: _,
+        deprecated,
+        servers,
+        errors,
+        param_structure,
         examples: _,
         extensions: _,
     } = method;
-    (name, (params, result))
+    (
+        name,
+        (
+            params,
+            result,
+            errors.unwrap_or_default(),
+            deprecated.unwrap_or_default(),
+            servers.unwrap_or_default(),
+            param_structure.unwrap_or_default(),
+        ),
+    )
+}
+
+/// Pick out the [`openrpc_types::Error`]s that are inlined rather than `$ref`erenced, keyed by
+/// their `code`. References into `components.errors` aren't resolved: referenced errors are left
+/// out, so changes to them aren't reported (unlike schema `$ref`s into `components.schemas`, which
+/// are still compared by feeding [`RootSchema::definitions`] to `json_schema_diff`).
+fn errors_by_code(errors: &[ReferenceOr<MethodError>]) -> BTreeMap<i64, &MethodError> {
+    errors
+        .iter()
+        .filter_map(|it| match it {
+            ReferenceOr::Item(error) => Some((error.code, error)),
+            ReferenceOr::Reference(_) => None,
+        })
+        .collect()
 }
 
 fn read(path: impl AsRef<Path>) -> anyhow::Result<OpenRPC> {
@@ -220,11 +500,13 @@ fn read(path: impl AsRef<Path>) -> anyhow::Result<OpenRPC> {
 
 mod summary {
     use super::RequiredChange;
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, BTreeSet};
 
     use itertools::EitherOrBoth;
     use json_schema_diff::JsonSchemaType;
     use nunny::NonEmpty;
+    use openrpc_types::{Error as MethodError, ParamStructure, Server};
+    use schemars::schema::{RootSchema, Schema};
     use serde::Serialize;
     use serde_json::Value;
 
@@ -238,14 +520,207 @@ mod summary {
         pub left: Vec<String>,
         #[serde(skip_serializing_if = "Vec::is_empty")]
         pub right: Vec<String>,
+        /// The version bump an author should make to `right` to reflect these changes,
+        /// taking the worst [`Compatibility`] verdict across every method.
+        pub recommended_bump: Bump,
+    }
+
+    /// A cumulative changelog across an ordered sequence of spec versions, built by
+    /// diffing each adjacent pair and rolling the results up into a single artifact.
+    #[derive(Serialize)]
+    pub struct Changelog {
+        pub transitions: Vec<Transition>,
+        pub history: BTreeMap<String, MethodHistory>,
+        /// The largest [`Bump`] recommended by any transition in the range.
+        pub recommended_bump: Bump,
+    }
+
+    /// The diff between two adjacent versions in a [`Changelog`].
+    #[derive(Serialize)]
+    pub struct Transition {
+        pub from: String,
+        pub to: String,
+        #[serde(flatten)]
+        pub summary: Summary,
+    }
+
+    /// Where a single method sits across the whole version range: the version it
+    /// appeared in, the version it was last changed in (if any), and the
+    /// version it was removed in (if it no longer exists). A method that is removed
+    /// and later added back starts a fresh entry at the version it returned in.
+    #[derive(Serialize)]
+    pub struct MethodHistory {
+        pub appeared: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_changed: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub removed: Option<String>,
+    }
+
+    /// Walk `transitions` in order, tracking when each method appeared, was last
+    /// changed, and was removed, seeding the initial set from `first_methods`.
+    pub fn history(
+        first_label: &str,
+        first_methods: &BTreeSet<String>,
+        transitions: &[Transition],
+    ) -> BTreeMap<String, MethodHistory> {
+        let mut history = first_methods
+            .iter()
+            .map(|name| {
+                (
+                    name.clone(),
+                    MethodHistory {
+                        appeared: first_label.to_owned(),
+                        last_changed: None,
+                        removed: None,
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        for transition in transitions {
+            for name in &transition.summary.right {
+                history.insert(
+                    name.clone(),
+                    MethodHistory {
+                        appeared: transition.to.clone(),
+                        last_changed: None,
+                        removed: None,
+                    },
+                );
+            }
+            for name in transition.summary.different.keys() {
+                if let Some(entry) = history.get_mut(name) {
+                    entry.last_changed = Some(transition.to.clone());
+                }
+            }
+            for name in &transition.summary.left {
+                if let Some(entry) = history.get_mut(name) {
+                    entry.removed = Some(transition.to.clone());
+                }
+            }
+        }
+
+        history
     }
 
     #[derive(Serialize)]
     pub struct MethodChange {
         #[serde(skip_serializing_if = "BTreeMap::is_empty")]
-        pub parameter: BTreeMap<usize, ContentDescriptorChange>,
+        pub parameter: BTreeMap<ParamKey, ParameterChange>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub result: Option<ContentDescriptorChange>,
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        pub errors: BTreeMap<i64, ErrorChange>,
+        #[serde(skip_serializing_if = "is_false")]
+        pub newly_deprecated: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub servers: Option<ServersChange>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub param_structure: Option<ParamStructureChange>,
+        pub compatibility: Compatibility,
+    }
+
+    fn is_false(it: &bool) -> bool {
+        !it
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum ErrorChange {
+        Added,
+        Removed,
+        Changed {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message: Option<(String, String)>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            data: Vec<Change>,
+        },
+    }
+
+    impl ErrorChange {
+        pub fn compatibility(&self) -> Compatibility {
+            match self {
+                ErrorChange::Added => Compatibility::Addition,
+                ErrorChange::Removed => Compatibility::Compatible,
+                ErrorChange::Changed { data, .. } => data
+                    .iter()
+                    .map(|it| it.compatibility)
+                    .max()
+                    .unwrap_or(Compatibility::Compatible),
+            }
+        }
+    }
+
+    /// Diffs an error's `message` and its `data` schema, treating `data` like a result (the
+    /// client is the one reading it, so a narrower `data` shape is breaking).
+    ///
+    /// `data` is only a JSON Schema by convention, not by the spec (it's documented as "a
+    /// Primitive or Structured value"), so it's only diffed when both sides actually parse
+    /// as one; anything else (absent, or a bare primitive) is left uncompared rather than
+    /// fed to `json_schema_diff`, which errors on non-schema input.
+    pub fn error_change(
+        left: &MethodError,
+        right: &MethodError,
+        left_definitions: &BTreeMap<String, Schema>,
+        right_definitions: &BTreeMap<String, Schema>,
+    ) -> Option<ErrorChange> {
+        let message =
+            (left.message != right.message).then(|| (left.message.clone(), right.message.clone()));
+        let data = match (
+            data_schema_json(left.data.as_ref(), left_definitions),
+            data_schema_json(right.data.as_ref(), right_definitions),
+        ) {
+            (Some(left), Some(right)) => json_schema_diff::diff(left, right)
+                .ok()
+                .and_then(|it| nunny::Vec::new(it).ok()),
+            _ => None,
+        };
+        if message.is_none() && data.is_none() {
+            return None;
+        }
+        Some(ErrorChange::Changed {
+            message,
+            data: data
+                .map(|it| {
+                    it.into_vec()
+                        .into_iter()
+                        .map(|it| change(it, Polarity::Result))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Parses `data` as a JSON Schema and, like content descriptor schemas, resolves its
+    /// `$ref`s against `definitions` so the same references used elsewhere in the document
+    /// work inside error `data` too.
+    fn data_schema_json(
+        data: Option<&Value>,
+        definitions: &BTreeMap<String, Schema>,
+    ) -> Option<Value> {
+        let mut schema: Schema = serde_json::from_value(data?.clone()).ok()?;
+        super::rewrite_schema_references::schema(&mut schema);
+        Some(
+            serde_json::to_value(&RootSchema {
+                meta_schema: None,
+                schema: schema.into_object(),
+                definitions: definitions.clone(),
+            })
+            .unwrap(),
+        )
+    }
+
+    #[derive(Serialize)]
+    pub struct ServersChange {
+        pub left: Vec<Server>,
+        pub right: Vec<Server>,
+    }
+
+    #[derive(Serialize)]
+    pub struct ParamStructureChange {
+        pub left: ParamStructure,
+        pub right: ParamStructure,
     }
 
     #[derive(Serialize)]
@@ -254,6 +729,36 @@ mod summary {
         pub changes: Vec<Change>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub required: Option<RequiredChange>,
+        pub compatibility: Compatibility,
+    }
+
+    /// Either a positional index (for by-position methods) or a
+    /// [`ContentDescriptor::name`](openrpc_types::ContentDescriptor::name) (for by-name methods),
+    /// so the output stays stable under reordering in the by-name case.
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    #[serde(untagged)]
+    pub enum ParamKey {
+        Index(usize),
+        Name(String),
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum ParameterChange {
+        Added { required: bool },
+        Removed,
+        Changed(ContentDescriptorChange),
+    }
+
+    impl ParameterChange {
+        pub fn compatibility(&self) -> Compatibility {
+            match self {
+                ParameterChange::Added { required: true } => Compatibility::Breaking,
+                ParameterChange::Added { required: false } => Compatibility::Addition,
+                ParameterChange::Removed => Compatibility::Compatible,
+                ParameterChange::Changed(it) => it.compatibility,
+            }
+        }
     }
 
     #[derive(Serialize)]
@@ -263,6 +768,7 @@ mod summary {
         pub kind: ChangeKind,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub of: Option<Subject>,
+        pub compatibility: Compatibility,
     }
 
     #[derive(Serialize)]
@@ -292,66 +798,146 @@ mod summary {
         RequiredAdd,
     }
 
-    impl From<json_schema_diff::Change> for Change {
-        fn from(value: json_schema_diff::Change) -> Self {
-            let json_schema_diff::Change { path, change } = value;
+    /// Whether a [`ContentDescriptor`](openrpc_types::ContentDescriptor) is read by existing
+    /// clients as an input (contravariant: accepting more is safe) or an output (covariant:
+    /// promising more is safe).
+    #[derive(Debug, Clone, Copy)]
+    pub enum Polarity {
+        Param,
+        Result,
+    }
 
-            use json_schema_diff::ChangeKind as Th;
-            let (kind, subject) = match change {
-                Th::TypeAdd { added } => (ChangeKind::TypeAdd, Some(Subject::Type(added))),
-                Th::TypeRemove { removed } => {
-                    (ChangeKind::TypeRemove, Some(Subject::Type(removed)))
-                }
-                Th::ConstAdd { added } => (ChangeKind::ConstAdd, Some(Subject::Const(added))),
-                Th::ConstRemove { removed } => {
-                    (ChangeKind::ConstRemove, Some(Subject::Const(removed)))
-                }
-                Th::PropertyAdd {
-                    lhs_additional_properties: _,
-                    added,
-                } => (ChangeKind::PropertyAdd, Some(Subject::Property(added))),
-                Th::PropertyRemove {
-                    lhs_additional_properties: _,
-                    removed,
-                } => (ChangeKind::PropertyRemove, Some(Subject::Property(removed))),
-                Th::RangeAdd { added: _ } => (ChangeKind::RangeAdd, None),
-                Th::RangeRemove { removed: _ } => (ChangeKind::RangeRemove, None),
-                Th::RangeChange {
-                    old_value: _,
-                    new_value: _,
-                } => (ChangeKind::RangeChange, None),
-                Th::TupleToArray { old_length: _ } => (ChangeKind::TupleToArray, None),
-                Th::ArrayToTuple { new_length: _ } => (ChangeKind::ArrayToTuple, None),
-                Th::TupleChange { new_length: _ } => (ChangeKind::TupleChange, None),
-                Th::RequiredRemove { property } => (
-                    ChangeKind::RequiredRemove,
-                    Some(Subject::Property(property)),
-                ),
-                Th::RequiredAdd { property } => {
-                    (ChangeKind::RequiredAdd, Some(Subject::Property(property)))
-                }
-            };
-            Self {
-                path,
-                kind,
-                of: subject,
+    /// A verdict on whether a change breaks clients written against the left-hand side.
+    ///
+    /// Ordered so that `max`-folding a set of verdicts picks the worst one, per the
+    /// `Breaking > Addition > Compatible` lattice.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum Compatibility {
+        Compatible,
+        Addition,
+        Breaking,
+    }
+
+    /// Ordered so that `max`-folding a set of bumps across many transitions picks the
+    /// largest one, per the `Major > Minor > Patch` lattice.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum Bump {
+        Patch,
+        Minor,
+        Major,
+    }
+
+    impl From<Compatibility> for Bump {
+        fn from(value: Compatibility) -> Self {
+            match value {
+                Compatibility::Compatible => Bump::Patch,
+                Compatibility::Addition => Bump::Minor,
+                Compatibility::Breaking => Bump::Major,
             }
         }
     }
 
-    impl From<EitherOrBoth<NonEmpty<Vec<json_schema_diff::Change>>, RequiredChange>>
-        for ContentDescriptorChange
-    {
-        fn from(
-            value: EitherOrBoth<NonEmpty<Vec<json_schema_diff::Change>>, RequiredChange>,
-        ) -> Self {
-            let (change, required) = value.left_and_right();
-            Self {
-                changes: change
-                    .map(|it| it.into_vec().into_iter().map(Into::into).collect())
-                    .unwrap_or_default(),
-                required,
+    fn classify_change_kind(kind: &ChangeKind, polarity: Polarity) -> Compatibility {
+        use ChangeKind::*;
+        use Compatibility::*;
+        match polarity {
+            // parameters are contravariant inputs: widening what the server accepts is safe,
+            // narrowing it breaks callers who rely on the wider shape
+            Polarity::Param => match kind {
+                TypeAdd | PropertyAdd | RangeAdd | RequiredRemove => Compatible,
+                TypeRemove | RangeRemove | RequiredAdd | ArrayToTuple => Breaking,
+                ConstRemove | PropertyRemove => Compatible,
+                ConstAdd | RangeChange | TupleToArray | TupleChange => Breaking,
+            },
+            // results are covariant outputs: the server promising less than before breaks
+            // callers who relied on it, promising more is purely additive
+            Polarity::Result => match kind {
+                TypeRemove | PropertyRemove | RequiredRemove => Breaking,
+                TypeAdd | PropertyAdd | RangeAdd | RequiredAdd | RangeRemove | ArrayToTuple => {
+                    Compatible
+                }
+                ConstAdd | ConstRemove | RangeChange | TupleToArray | TupleChange => Breaking,
+            },
+        }
+    }
+
+    fn classify_required(change: &RequiredChange, polarity: Polarity) -> Compatibility {
+        match (polarity, change) {
+            (Polarity::Param, RequiredChange::Right) => Compatibility::Breaking,
+            (Polarity::Param, RequiredChange::Left) => Compatibility::Compatible,
+            (Polarity::Result, RequiredChange::Left) => Compatibility::Breaking,
+            (Polarity::Result, RequiredChange::Right) => Compatibility::Compatible,
+        }
+    }
+
+    fn change(value: json_schema_diff::Change, polarity: Polarity) -> Change {
+        let json_schema_diff::Change { path, change } = value;
+
+        use json_schema_diff::ChangeKind as Th;
+        let (kind, subject) = match change {
+            Th::TypeAdd { added } => (ChangeKind::TypeAdd, Some(Subject::Type(added))),
+            Th::TypeRemove { removed } => (ChangeKind::TypeRemove, Some(Subject::Type(removed))),
+            Th::ConstAdd { added } => (ChangeKind::ConstAdd, Some(Subject::Const(added))),
+            Th::ConstRemove { removed } => (ChangeKind::ConstRemove, Some(Subject::Const(removed))),
+            Th::PropertyAdd {
+                lhs_additional_properties: _,
+                added,
+            } => (ChangeKind::PropertyAdd, Some(Subject::Property(added))),
+            Th::PropertyRemove {
+                lhs_additional_properties: _,
+                removed,
+            } => (ChangeKind::PropertyRemove, Some(Subject::Property(removed))),
+            Th::RangeAdd { added: _ } => (ChangeKind::RangeAdd, None),
+            Th::RangeRemove { removed: _ } => (ChangeKind::RangeRemove, None),
+            Th::RangeChange {
+                old_value: _,
+                new_value: _,
+            } => (ChangeKind::RangeChange, None),
+            Th::TupleToArray { old_length: _ } => (ChangeKind::TupleToArray, None),
+            Th::ArrayToTuple { new_length: _ } => (ChangeKind::ArrayToTuple, None),
+            Th::TupleChange { new_length: _ } => (ChangeKind::TupleChange, None),
+            Th::RequiredRemove { property } => (
+                ChangeKind::RequiredRemove,
+                Some(Subject::Property(property)),
+            ),
+            Th::RequiredAdd { property } => {
+                (ChangeKind::RequiredAdd, Some(Subject::Property(property)))
             }
+        };
+        let compatibility = classify_change_kind(&kind, polarity);
+        Change {
+            path,
+            kind,
+            of: subject,
+            compatibility,
+        }
+    }
+
+    pub fn content_descriptor_change(
+        value: EitherOrBoth<NonEmpty<Vec<json_schema_diff::Change>>, RequiredChange>,
+        polarity: Polarity,
+    ) -> ContentDescriptorChange {
+        let (changes, required) = value.left_and_right();
+        let changes: Vec<Change> = changes
+            .map(|it| {
+                it.into_vec()
+                    .into_iter()
+                    .map(|it| change(it, polarity))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let compatibility = changes
+            .iter()
+            .map(|it| it.compatibility)
+            .chain(required.as_ref().map(|it| classify_required(it, polarity)))
+            .max()
+            .unwrap_or(Compatibility::Compatible);
+        ContentDescriptorChange {
+            changes,
+            required,
+            compatibility,
         }
     }
 }